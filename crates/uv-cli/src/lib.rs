@@ -58,6 +58,24 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum VenvFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -881,7 +899,7 @@ pub enum CacheCommand {
     ///
     /// Note that it is important for performance for the cache directory to be located on the same
     /// file system as the Python environment uv is operating on.
-    Dir,
+    Dir(DirArgs),
     /// Show the cache size.
     ///
     /// Displays the total size of the cache directory. This includes all downloaded and built
@@ -930,11 +948,24 @@ pub struct PruneArgs {
     pub force: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct DirArgs {
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheFormat::default())]
+    pub output_format: CacheFormat,
+}
+
 #[derive(Args, Debug)]
 pub struct SizeArgs {
     /// Display the cache size in human-readable format (e.g., `1.2 GiB` instead of raw bytes).
     #[arg(long = "human", short = 'H', alias = "human-readable")]
     pub human: bool,
+
+    /// Select the output format.
+    ///
+    /// When `json` is used, the cache directory and its size in bytes are written to stdout.
+    #[arg(long, value_enum, default_value_t = CacheFormat::default(), conflicts_with = "human")]
+    pub output_format: CacheFormat,
 }
 
 #[derive(Args)]
@@ -3180,6 +3211,13 @@ pub struct VenvArgs {
     #[arg(long, overrides_with("relocatable"), hide = true)]
     pub no_relocatable: bool,
 
+    /// Select the output format.
+    ///
+    /// When `json` is used, a description of the created virtual environment, including its path
+    /// and Python interpreter, is written to stdout.
+    #[arg(long, value_enum, default_value_t = VenvFormat::default())]
+    pub output_format: VenvFormat,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
@@ -1,18 +1,55 @@
 use owo_colors::OwoColorize;
+use serde::Serialize;
 use std::fmt::Write;
 
 use uv_cache::Cache;
-use uv_fs::Simplified;
+use uv_cli::CacheFormat;
+use uv_fs::{PortablePathBuf, Simplified};
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
+#[derive(Debug, Serialize)]
+struct CacheDirReport {
+    /// The path to the cache directory.
+    path: PortablePathBuf,
+}
+
 /// Show the cache directory.
-pub(crate) fn cache_dir(cache: &Cache, printer: Printer) -> anyhow::Result<ExitStatus> {
-    writeln!(
-        printer.stdout(),
-        "{}",
-        cache.root().simplified_display().cyan()
-    )?;
+pub(crate) fn cache_dir(
+    cache: &Cache,
+    output_format: CacheFormat,
+    printer: Printer,
+    preview: Preview,
+) -> anyhow::Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::JsonOutput) && matches!(output_format, CacheFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    match output_format {
+        CacheFormat::Text => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                cache.root().simplified_display().cyan()
+            )?;
+        }
+        CacheFormat::Json => {
+            let report = CacheDirReport {
+                path: cache.root().into(),
+            };
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
     Ok(ExitStatus::Success)
 }
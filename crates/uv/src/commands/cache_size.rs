@@ -2,17 +2,29 @@ use std::fmt::Write;
 
 use anyhow::Result;
 use diskus::DiskUsage;
+use serde::Serialize;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 use uv_cache::Cache;
+use uv_cli::CacheFormat;
+use uv_fs::PortablePathBuf;
 use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
+#[derive(Debug, Serialize)]
+struct CacheSizeReport {
+    /// The path to the cache directory.
+    path: PortablePathBuf,
+    /// The total size of the cache directory, in bytes.
+    bytes: u64,
+}
+
 /// Display the total size of the cache.
 pub(crate) fn cache_size(
     cache: &Cache,
     human_readable: bool,
+    output_format: CacheFormat,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -23,24 +35,42 @@ pub(crate) fn cache_size(
         );
     }
 
-    if !cache.root().exists() {
-        if human_readable {
-            writeln!(printer.stdout_important(), "0B")?;
-        } else {
-            writeln!(printer.stdout_important(), "0")?;
-        }
-        return Ok(ExitStatus::Success);
+    if !preview.is_enabled(PreviewFeature::JsonOutput) && matches!(output_format, CacheFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
     }
 
-    let disk_usage = DiskUsage::new(vec![cache.root().to_path_buf()]);
-
-    let total_bytes = disk_usage.count_ignoring_errors();
-
-    if human_readable {
-        let (bytes, unit) = human_readable_bytes(total_bytes);
-        writeln!(printer.stdout_important(), "{bytes:.1}{unit}")?;
+    let total_bytes = if cache.root().exists() {
+        DiskUsage::new(vec![cache.root().to_path_buf()]).count_ignoring_errors()
     } else {
-        writeln!(printer.stdout_important(), "{total_bytes}")?;
+        0
+    };
+
+    match output_format {
+        CacheFormat::Json => {
+            let report = CacheSizeReport {
+                path: cache.root().into(),
+                bytes: total_bytes,
+            };
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+        CacheFormat::Text if human_readable && total_bytes == 0 => {
+            writeln!(printer.stdout_important(), "0B")?;
+        }
+        CacheFormat::Text if human_readable => {
+            let (bytes, unit) = human_readable_bytes(total_bytes);
+            writeln!(printer.stdout_important(), "{bytes:.1}{unit}")?;
+        }
+        CacheFormat::Text => {
+            writeln!(printer.stdout_important(), "{total_bytes}")?;
+        }
     }
 
     Ok(ExitStatus::Success)
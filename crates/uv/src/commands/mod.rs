@@ -87,6 +87,7 @@ pub(crate) mod pip;
 mod project;
 mod publish;
 mod python;
+mod report;
 pub(crate) mod reporters;
 #[cfg(feature = "self-update")]
 mod self_update;
@@ -48,6 +48,7 @@ use crate::commands::project::{
     UniversalState, default_dependency_groups, detect_conflicts, script_extra_build_requires,
     script_specification, update_environment,
 };
+use crate::commands::report::EnvironmentReport;
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 use crate::settings::{
@@ -1174,49 +1175,6 @@ impl LockAction {
     }
 }
 
-#[derive(Serialize, Debug)]
-struct PythonReport {
-    path: PortablePathBuf,
-    version: uv_pep508::StringVersion,
-    implementation: String,
-}
-
-impl From<&uv_python::Interpreter> for PythonReport {
-    fn from(interpreter: &uv_python::Interpreter) -> Self {
-        Self {
-            path: interpreter.sys_executable().into(),
-            version: interpreter.python_full_version().clone(),
-            implementation: interpreter.implementation_name().to_string(),
-        }
-    }
-}
-
-impl PythonReport {
-    /// Set the path for this Python report.
-    #[must_use]
-    fn with_path(mut self, path: PortablePathBuf) -> Self {
-        self.path = path;
-        self
-    }
-}
-
-#[derive(Serialize, Debug)]
-struct EnvironmentReport {
-    /// The path to the environment.
-    path: PortablePathBuf,
-    /// The Python interpreter for the environment.
-    python: PythonReport,
-}
-
-impl From<&PythonEnvironment> for EnvironmentReport {
-    fn from(env: &PythonEnvironment) -> Self {
-        Self {
-            python: PythonReport::from(env.interpreter()),
-            path: env.root().into(),
-        }
-    }
-}
-
 impl From<&SyncEnvironment> for EnvironmentReport {
     fn from(env: &SyncEnvironment) -> Self {
         let report = Self::from(&**env);
@@ -1230,20 +1188,6 @@ impl From<&SyncEnvironment> for EnvironmentReport {
     }
 }
 
-impl EnvironmentReport {
-    /// Set the path for this environment report.
-    #[must_use]
-    fn with_path(mut self, path: PortablePathBuf) -> Self {
-        let python_path = &self.python.path;
-        if let Ok(python_path) = python_path.as_ref().strip_prefix(self.path) {
-            let new_path = path.as_ref().to_path_buf().join(python_path);
-            self.python = self.python.with_path(new_path.as_path().into());
-        }
-        self.path = path;
-        self
-    }
-}
-
 /// The report for a sync operation.
 #[derive(Serialize, Debug)]
 struct SyncReport {
@@ -22,7 +22,8 @@ use crate::commands::ExitStatus;
 use crate::printer::Printer;
 use crate::settings::PythonListKinds;
 
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
 enum Kind {
     Download,
     Managed,
@@ -49,6 +50,7 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    kind: Kind,
 }
 
 /// List available Python installations.
@@ -221,14 +223,14 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, uri));
+        include.push((key, kind, uri));
     }
 
     match output_format {
         PythonListFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
+                .map(|(key, kind, uri)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
@@ -266,6 +268,7 @@ pub(crate) async fn list(
                         os: key.os().to_string(),
                         variant: key.variant().to_string(),
                         libc: key.libc().to_string(),
+                        kind: **kind,
                     })
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -275,9 +278,9 @@ pub(crate) async fn list(
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, _, _)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, _, uri) in include {
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
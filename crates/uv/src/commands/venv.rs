@@ -8,6 +8,7 @@ use owo_colors::OwoColorize;
 use thiserror::Error;
 
 use uv_cache::Cache;
+use uv_cli::VenvFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, IndexStrategy,
@@ -21,7 +22,7 @@ use uv_distribution_types::{
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_normalize::DefaultGroups;
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
 };
@@ -37,6 +38,7 @@ use crate::commands::ExitStatus;
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
 use crate::commands::pip::operations::{Changelog, report_interpreter};
 use crate::commands::project::{WorkspacePython, validate_project_requires_python};
+use crate::commands::report::EnvironmentReport;
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
 
@@ -83,8 +85,17 @@ pub(crate) async fn venv(
     cache: &Cache,
     printer: Printer,
     relocatable: bool,
+    output_format: VenvFormat,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::JsonOutput) && matches!(output_format, VenvFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
     let workspace_cache = WorkspaceCache::default();
     let project = if no_project {
         None
@@ -340,5 +351,10 @@ pub(crate) async fn venv(
         writeln!(printer.stderr(), "Activate with: {}", act.green())?;
     }
 
+    if matches!(output_format, VenvFormat::Json) {
+        let report = serde_json::to_string_pretty(&EnvironmentReport::from(&venv))?;
+        writeln!(printer.stdout_important(), "{report}")?;
+    }
+
     Ok(ExitStatus::Success)
 }
@@ -1120,11 +1120,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_prune(args.ci, args.force, cache, printer).await
         }
         Commands::Cache(CacheNamespace {
-            command: CacheCommand::Dir,
-        }) => commands::cache_dir(&cache, printer),
+            command: CacheCommand::Dir(args),
+        }) => commands::cache_dir(&cache, args.output_format, printer, globals.preview),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
-        }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        }) => commands::cache_size(
+            &cache,
+            args.human,
+            args.output_format,
+            printer,
+            globals.preview,
+        ),
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1254,6 +1260,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                         .preview
                         .is_enabled(PreviewFeature::RelocatableEnvsDefault)
                         && !args.no_relocatable),
+                args.output_format,
                 globals.preview,
             )
             .await
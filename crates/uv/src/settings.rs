@@ -17,8 +17,8 @@ use uv_cli::{
     PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
     PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
     PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
-    VersionBumpSpec, VersionFormat,
+    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VenvFormat,
+    VersionArgs, VersionBumpSpec, VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
@@ -3354,6 +3354,7 @@ pub(crate) struct VenvSettings {
     pub(crate) relocatable: bool,
     pub(crate) no_relocatable: bool,
     pub(crate) no_project: bool,
+    pub(crate) output_format: VenvFormat,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -3378,6 +3379,7 @@ impl VenvSettings {
             system_site_packages,
             relocatable,
             no_relocatable,
+            output_format,
             index_args,
             index_strategy,
             keyring_provider,
@@ -3404,6 +3406,7 @@ impl VenvSettings {
             no_project,
             relocatable,
             no_relocatable,
+            output_format,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -6,7 +6,34 @@ use assert_fs::prelude::*;
 use std::process::Command;
 
 #[cfg(unix)]
-use uv_test::{get_bin, uv_snapshot};
+use uv_test::get_bin;
+use uv_test::uv_snapshot;
+
+/// `cache dir` shows the cache directory, optionally as JSON.
+#[test]
+fn cache_dir() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.command().arg("cache").arg("dir"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.command().arg("cache").arg("dir").arg("--preview-features").arg("json-output").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "path": "[CACHE_DIR]/"
+    }
+
+    ----- stderr -----
+    "#);
+}
 
 /// When the cache directory cannot be created (e.g., due to permissions), we should show a
 /// chained error message that indicates we failed to initialize the cache.
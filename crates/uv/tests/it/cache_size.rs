@@ -57,3 +57,42 @@ fn cache_size_with_packages_human() {
     ----- stderr -----
     ");
 }
+
+/// Test that `cache size --output-format json` reports the cache directory and its size in bytes.
+#[test]
+fn cache_size_json() {
+    let context = uv_test::test_context!("3.12");
+
+    // Clean cache first to ensure truly empty state
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.cache_size().arg("--preview-features").arg("cache-size,json-output").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "path": "[CACHE_DIR]/",
+      "bytes": 0
+    }
+
+    ----- stderr -----
+    "#);
+
+    // Install a requirement to populate the cache.
+    context.pip_install().arg("iniconfig").assert().success();
+
+    let mut filters = context.filters();
+    filters.push((r#""bytes": \d+"#, r#""bytes": [SIZE]"#));
+
+    uv_snapshot!(&filters, context.cache_size().arg("--preview-features").arg("cache-size,json-output").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "path": "[CACHE_DIR]/",
+      "bytes": [SIZE]
+    }
+
+    ----- stderr -----
+    "#);
+}
@@ -485,6 +485,47 @@ fn python_list_downloads_installed() {
     ");
 }
 
+#[test]
+#[cfg(all(unix, feature = "test-python-managed"))]
+fn python_list_json() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names()
+        .with_managed_python_dirs();
+
+    let mut filters = context.filters();
+    filters.push((r#""url":"[^"]+""#, r#""url":"[URL]""#));
+    filters.push((r#""os":"[^"]+""#, r#""os":"[OS]""#));
+    filters.push((r#""arch":"[^"]+""#, r#""arch":"[ARCH]""#));
+    filters.push((r#""libc":"[^"]+""#, r#""libc":"[LIBC]""#));
+
+    // A download is reported with its URL and the `download` kind
+    uv_snapshot!(&filters, context.python_list().arg("cpython@3.10").arg("--only-downloads").arg("--output-format").arg("json").env_remove(EnvVars::UV_PYTHON_DOWNLOADS), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"key":"cpython-3.10.19-[PLATFORM]","version":"3.10.19","version_parts":{"major":3,"minor":10,"patch":19},"path":null,"symlink":null,"url":"[URL]","os":"[OS]","variant":"default","implementation":"cpython","arch":"[ARCH]","libc":"[LIBC]","kind":"download"}]
+
+    ----- stderr -----
+    "#);
+
+    // Install a Python version
+    context.python_install().arg("3.10").assert().success();
+
+    // Then, it should be reported with its path and the `managed` kind
+    uv_snapshot!(&filters, context.python_list().arg("cpython@3.10").arg("--only-installed").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"key":"cpython-3.10.19-[PLATFORM]","version":"3.10.19","version_parts":{"major":3,"minor":10,"patch":19},"path":"managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]","symlink":null,"url":null,"os":"[OS]","variant":"default","implementation":"cpython","arch":"[ARCH]","libc":"[LIBC]","kind":"managed"}]
+
+    ----- stderr -----
+    "#);
+}
+
 #[tokio::test]
 async fn python_list_remote_python_downloads_json_url() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&[]);
@@ -70,6 +70,43 @@ fn create_venv() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+#[test]
+fn create_venv_json() {
+    let context = uv_test::test_context_with_versions!(&["3.12"])
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin();
+
+    // The created environment is described on stdout
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("json-output")
+        .arg("--output-format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "path": "[VENV]/",
+      "python": {
+        "path": "[VENV]/[BIN]/[PYTHON]",
+        "version": "3.12.[X]",
+        "implementation": "cpython"
+      }
+    }
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "#
+    );
+
+    context.venv.assert(predicates::path::is_dir());
+}
+
 #[test]
 fn create_venv_313() {
     let context = uv_test::test_context_with_versions!(&["3.13"]);